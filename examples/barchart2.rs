@@ -17,11 +17,14 @@ use std::{
 };
 
 struct App<'a> {
-    data: Vec<u64>,
-    data2: Vec<u64>,
+    data: Vec<i64>,
+    data2: Vec<i64>,
     labels: Vec<&'a str>,
     styles: Vec<Style>,
     value_styles: Vec<Style>,
+    /// Paged through on every tick to demo `BarChart2::scroll_offset`, as if a live feed kept
+    /// pushing new groups in past the right edge.
+    scroll_offset: usize,
 }
 
 impl<'a> App<'a> {
@@ -38,10 +41,13 @@ impl<'a> App<'a> {
                 Style::default().bg(Color::Green).fg(Color::Black),
                 Style::default().bg(Color::Yellow).fg(Color::Black),
             ],
+            scroll_offset: 0,
         }
     }
 
-    fn on_tick(&mut self) {}
+    fn on_tick(&mut self) {
+        self.scroll_offset = (self.scroll_offset + 1) % self.data.len();
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -115,7 +121,9 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
         .bar_styles(&app.styles)
         .labels(&app.labels)
         .value_format(|v| (v + 20).to_string())
-        .value_styles(&app.value_styles);
+        .value_styles(&app.value_styles)
+        .series_names(&["Data1", "Data2"])
+        .show_legend(true);
     f.render_widget(barchart, chunks[0]);
 
     let chunks = Layout::default()
@@ -130,22 +138,20 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
         .bar_width(5)
         .group_gap(3)
         .bar_styles(&app.styles)
-        .value_styles(&app.value_styles);
+        .value_styles(&app.value_styles)
+        .direction(Direction::Horizontal)
+        .labels(&app.labels);
 
     f.render_widget(barchart, chunks[0]);
 
-    /*let barchart = BarChart2::default()
+    let barchart = BarChart2::default()
         .block(Block::default().title("Data3").borders(Borders::ALL))
         .add_data(&app.data)
         .add_data(&app.data2)
-        .bar_style(Style::default().fg(Color::Red))
         .bar_width(7)
-        .bar_gap(0)
-        .value_style(Style::default().bg(Color::Red))
-        .label_style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::ITALIC),
-        );
-    f.render_widget(barchart, chunks[1]);*/
+        .bar_styles(&app.styles)
+        .value_styles(&app.value_styles)
+        .labels(&app.labels)
+        .scroll_offset(app.scroll_offset);
+    f.render_widget(barchart, chunks[1]);
 }
@@ -1,6 +1,6 @@
 use crate::{
     buffer::Buffer,
-    layout::{Rect, Size},
+    layout::{Direction, Rect, Size},
     style::Style,
     symbols::{self},
     widgets::{Block, Widget},
@@ -10,6 +10,45 @@ use unicode_width::UnicodeWidthStr;
 
 use super::SizeHint;
 
+/// Controls how the series of a [`BarChart2`] column are arranged.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum BarGrouping {
+    /// Series are drawn side-by-side, each in its own column.
+    #[default]
+    Grouped,
+    /// Series are stacked on top of one another in a single column.
+    Stacked,
+}
+
+/// A set of symbols used to draw a partially filled horizontal bar, mirroring
+/// [`symbols::bar::Set`] for the [`Direction::Horizontal`] rendering of [`BarChart2`].
+#[derive(Debug, Clone, Copy)]
+struct HorizontalBarSet {
+    empty: &'static str,
+    one_eighth: &'static str,
+    one_quarter: &'static str,
+    three_eighths: &'static str,
+    half: &'static str,
+    five_eighths: &'static str,
+    three_quarters: &'static str,
+    seven_eighths: &'static str,
+    full: &'static str,
+}
+
+/// Left-aligned eighth-block symbols, used to render [`BarChart2`] bars that grow
+/// left-to-right.
+const HORIZONTAL_NINE_LEVELS: HorizontalBarSet = HorizontalBarSet {
+    empty: " ",
+    one_eighth: "▏",
+    one_quarter: "▎",
+    three_eighths: "▍",
+    half: "▌",
+    five_eighths: "▋",
+    three_quarters: "▊",
+    seven_eighths: "▉",
+    full: "█",
+};
+
 /// Display multiple bars in a single widgets
 ///
 /// # Examples
@@ -36,6 +75,16 @@ pub struct BarChart2<'a> {
     /// The gap between each bar
     bar_gap: u16,
     group_gap: u16,
+    /// How the series within a column are arranged
+    grouping: BarGrouping,
+    /// Whether bars grow bottom-to-top or left-to-right
+    direction: Direction,
+    /// Number of leading groups to skip before fitting the rest to the available area
+    scroll_offset: usize,
+    /// Names of each series, used to render the legend
+    series_names: &'a [&'a str],
+    /// Whether to render a legend mapping `bar_styles` to `series_names`
+    show_legend: bool,
     /// Set of symbols used to display the data
     bar_set: symbols::bar::Set,
     /// Style of the bars
@@ -47,17 +96,18 @@ pub struct BarChart2<'a> {
     /// Style for the widget
     style: Style,
     /// Slice of value pair to plot on the chart
-    data: Vec<Vec<u64>>,
+    data: Vec<Vec<i64>>,
 
     labels: &'a [&'a str],
-    /// Value necessary for a bar to reach the maximum height (if no value is specified,
-    /// the maximum value in the data is taken as reference)
+    /// Maximum absolute magnitude a bar can reach (if no value is specified, the largest
+    /// magnitude in the data, or the largest column sum for [`BarGrouping::Stacked`], is taken
+    /// as reference)
     max: Option<u64>,
     /// Values to display on the bar (computed when the data is passed to the widget)
-    format: fn(u64) -> String,
+    format: fn(i64) -> String,
 }
 
-fn format_value(value: u64) -> String {
+fn format_value(value: i64) -> String {
     value.to_string()
 }
 
@@ -72,6 +122,11 @@ impl<'a> Default for BarChart2<'a> {
             bar_width: 1,
             bar_gap: 1,
             group_gap: 1,
+            grouping: BarGrouping::Grouped,
+            direction: Direction::Vertical,
+            scroll_offset: 0,
+            series_names: &[],
+            show_legend: false,
             bar_set: symbols::bar::NINE_LEVELS,
             value_styles: &[],
             label_style: Style::default(),
@@ -82,7 +137,9 @@ impl<'a> Default for BarChart2<'a> {
 }
 
 impl<'a> BarChart2<'a> {
-    pub fn add_data(mut self, data: &[u64]) -> BarChart2<'a> {
+    /// Adds a series of values, one per bar group, supporting negative values which are drawn
+    /// below a zero baseline (left of it in [`Direction::Horizontal`]).
+    pub fn add_data(mut self, data: &[i64]) -> BarChart2<'a> {
         if self.data.is_empty() {
             self.data = data.iter().map(|&v| vec![v]).collect();
         } else {
@@ -98,11 +155,12 @@ impl<'a> BarChart2<'a> {
         self
     }
 
-    pub fn value_format(mut self, value_format: fn(u64) -> String) -> BarChart2<'a> {
+    pub fn value_format(mut self, value_format: fn(i64) -> String) -> BarChart2<'a> {
         self.format = value_format;
         self
     }
 
+    /// Sets the absolute magnitude a bar reaches the edge of the chart area at.
     pub fn max(mut self, max: u64) -> BarChart2<'a> {
         self.max = Some(max);
         self
@@ -128,6 +186,42 @@ impl<'a> BarChart2<'a> {
         self
     }
 
+    pub fn grouping(mut self, grouping: BarGrouping) -> BarChart2<'a> {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets the direction bars grow in: [`Direction::Vertical`] (the default) grows bars
+    /// bottom-to-top, [`Direction::Horizontal`] grows them left-to-right.
+    pub fn direction(mut self, direction: Direction) -> BarChart2<'a> {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets how many leading groups to skip, letting a dataset wider than the chart area be
+    /// paged through (e.g. on key events) instead of always showing the first groups that fit.
+    pub fn scroll_offset(mut self, scroll_offset: usize) -> BarChart2<'a> {
+        self.scroll_offset = scroll_offset;
+        self
+    }
+
+    /// Sets the name of each series, shown next to its swatch when [`show_legend`] is enabled.
+    ///
+    /// [`show_legend`]: BarChart2::show_legend
+    pub fn series_names(mut self, series_names: &'a [&'a str]) -> BarChart2<'a> {
+        self.series_names = series_names;
+        self
+    }
+
+    /// Enables a compact legend mapping each `bar_styles` entry to its `series_names` label.
+    ///
+    /// The legend consumes one row of `chart_area`; it is skipped when the area is too small
+    /// to fit both the legend and at least one bar row.
+    pub fn show_legend(mut self, show_legend: bool) -> BarChart2<'a> {
+        self.show_legend = show_legend;
+        self
+    }
+
     pub fn bar_set(mut self, bar_set: symbols::bar::Set) -> BarChart2<'a> {
         self.bar_set = bar_set;
         self
@@ -171,109 +265,360 @@ impl<'a> Widget for BarChart2<'a> {
             return;
         }
 
-        let max = self.max.unwrap_or_else(|| {
-            self.data
-                .iter()
-                .map(|t| t.iter().max().copied().unwrap_or_default())
-                .max()
-                .unwrap_or_default()
-        });
+        match self.direction {
+            Direction::Vertical => self.render_vertical(chart_area, buf),
+            Direction::Horizontal => {
+                if chart_area.width < 2 {
+                    return;
+                }
+                self.render_horizontal(chart_area, buf);
+            }
+        }
+    }
+}
+
+impl<'a> BarChart2<'a> {
+    fn render_vertical(self, area: Rect, buf: &mut Buffer) {
+        let show_legend =
+            self.show_legend && !self.series_names.is_empty() && area.height > 2;
+        if show_legend {
+            self.render_legend(Rect::new(area.x, area.y, area.width, 1), buf);
+        }
+        let legend_rows = u16::from(show_legend);
+        let chart_area = Rect::new(
+            area.x,
+            area.y + legend_rows,
+            area.width,
+            area.height - legend_rows,
+        );
 
         let bars_per_column = self.data[0].len();
+        let scroll_offset = min(self.scroll_offset, self.data.len() - 1);
+
+        let column_footprint = match self.grouping {
+            BarGrouping::Grouped => {
+                (self.bar_width + self.bar_gap) * bars_per_column as u16 + self.group_gap
+            }
+            BarGrouping::Stacked => self.bar_width + self.group_gap,
+        };
 
         let max_index = min(
             (chart_area.width + self.group_gap + self.bar_gap) as usize
-                / ((self.bar_width + self.bar_gap) * bars_per_column as u16 + self.group_gap)
-                    as usize,
-            self.data.len(),
+                / column_footprint as usize,
+            self.data.len() - scroll_offset,
         );
 
-        let mut data: Vec<Vec<u64>> = self
-            .data
-            .iter()
-            .take(max_index)
-            .map(|bars| {
-                bars.iter()
-                    .map(|v| v * u64::from(chart_area.height - 1) * 8 / std::cmp::max(max, 1))
-                    .collect()
-            })
-            .collect::<Vec<Vec<u64>>>();
+        let bar_rows = chart_area.height - 1;
+        // For `Grouped` data each bar is scaled on its own, so the reference magnitude is the
+        // single largest value. For `Stacked` data a column's segments are drawn on top of one
+        // another, so the reference magnitude is the largest *column sum* instead, matching the
+        // grouping-aware `max` semantics `BarGrouping::Stacked` was introduced with.
+        let (default_max_positive, default_max_negative) = match self.grouping {
+            BarGrouping::Grouped => (
+                self.data
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|&v| v > 0)
+                    .map(|v| v.unsigned_abs())
+                    .max()
+                    .unwrap_or(0),
+                self.data
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|&v| v < 0)
+                    .map(|v| v.unsigned_abs())
+                    .max()
+                    .unwrap_or(0),
+            ),
+            BarGrouping::Stacked => (
+                self.data
+                    .iter()
+                    .map(|column| {
+                        column
+                            .iter()
+                            .copied()
+                            .filter(|&v| v > 0)
+                            .map(|v| v.unsigned_abs())
+                            .sum::<u64>()
+                    })
+                    .max()
+                    .unwrap_or(0),
+                self.data
+                    .iter()
+                    .map(|column| {
+                        column
+                            .iter()
+                            .copied()
+                            .filter(|&v| v < 0)
+                            .map(|v| v.unsigned_abs())
+                            .sum::<u64>()
+                    })
+                    .max()
+                    .unwrap_or(0),
+            ),
+        };
+        // Only let a custom `max()` override a direction that actually has data to cap —
+        // otherwise an all-positive chart would lose half its rows to an unreachable negative
+        // zone just because the caller set a ceiling.
+        let max_positive = if default_max_positive > 0 {
+            self.max.unwrap_or(default_max_positive)
+        } else {
+            0
+        };
+        let max_negative = if default_max_negative > 0 {
+            self.max.unwrap_or(default_max_negative)
+        } else {
+            0
+        };
+
+        // Split `bar_rows` proportionally between the two directions instead of complementing
+        // one off the other, so a chart whose largest magnitude happens to be positive doesn't
+        // starve negative bars (or vice versa) of any rows.
+        let magnitude_total = max_positive + max_negative;
+        let positive_rows = if magnitude_total == 0 {
+            bar_rows
+        } else {
+            min(
+                (u64::from(bar_rows) * max_positive / magnitude_total) as u16,
+                bar_rows,
+            )
+        };
+        let negative_rows = bar_rows - positive_rows;
+        let baseline = chart_area.top() + positive_rows;
 
         let defaul_style = Style::default();
-        for j in (0..chart_area.height - 1).rev() {
-            let mut i = 0usize;
-            let mut x_offset = 0u16;
-            for d in data.iter_mut() {
-                for (data_type, d) in d.iter_mut().enumerate() {
-                    let symbol = match d {
-                        0 => self.bar_set.empty,
-                        1 => self.bar_set.one_eighth,
-                        2 => self.bar_set.one_quarter,
-                        3 => self.bar_set.three_eighths,
-                        4 => self.bar_set.half,
-                        5 => self.bar_set.five_eighths,
-                        6 => self.bar_set.three_quarters,
-                        7 => self.bar_set.seven_eighths,
-                        _ => self.bar_set.full,
-                    };
-
-                    let bar_style = self.bar_styles.get(data_type).unwrap_or(&defaul_style);
-
-                    for x in 0..self.bar_width {
-                        buf.get_mut(
-                            chart_area.left()
-                                + i as u16 * (self.bar_width + self.bar_gap)
-                                + x
-                                + x_offset,
-                            chart_area.top() + j,
-                        )
+
+        // A visible zero line: rows above are positive, rows below (including this one) are
+        // negative, so a bar with no opposite-sign sibling still shows where zero sits.
+        for x in chart_area.left()..chart_area.right() {
+            buf.get_mut(x, baseline)
+                .set_symbol(symbols::line::NORMAL.horizontal)
+                .set_style(self.label_style);
+        }
+
+        // Terminal fonts rarely ship a full set of upper-eighth block glyphs, so negative bars
+        // reuse the same (bottom-aligned) symbols as positive ones for their partial row.
+        let eighths_to_symbol = |eighths: u64| match eighths {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
+        };
+
+        let eighths_of = |value: i64| -> u64 {
+            if value > 0 && max_positive > 0 {
+                value.unsigned_abs() * u64::from(positive_rows) * 8 / max_positive
+            } else if value < 0 && max_negative > 0 {
+                value.unsigned_abs() * u64::from(negative_rows) * 8 / max_negative
+            } else {
+                0
+            }
+        };
+
+        // A custom `max()` smaller than the data can make `eighths_of` scale a value past the
+        // rows actually available (the bar would reach past `positive_rows`/`negative_rows`), so
+        // `Grouped` bars clamp to their fixed share of `bar_rows` the same way `Stacked` segments
+        // clamp to whatever's left in the column.
+        let grouped_eighths_of = |value: i64| -> u64 {
+            let available = if value >= 0 {
+                u64::from(positive_rows) * 8
+            } else {
+                u64::from(negative_rows) * 8
+            };
+            min(eighths_of(value), available)
+        };
+
+        // `edge` is the baseline-adjacent row the segment grows away from: upward if
+        // `positive`, downward otherwise.
+        let draw_segment = |buf: &mut Buffer, left: u16, edge: u16, positive: bool, eighths: u64, bar_style: Style| {
+            let full_rows = (eighths / 8) as u16;
+            let remainder = (eighths % 8) as u16;
+            for row in 0..full_rows {
+                let y = if positive { edge - 1 - row } else { edge + row };
+                for x in 0..self.bar_width {
+                    buf.get_mut(left + x, y)
+                        .set_symbol(self.bar_set.full)
+                        .set_style(bar_style);
+                }
+            }
+            if remainder > 0 {
+                let y = if positive {
+                    edge - 1 - full_rows
+                } else {
+                    edge + full_rows
+                };
+                let symbol = eighths_to_symbol(u64::from(remainder));
+                for x in 0..self.bar_width {
+                    buf.get_mut(left + x, y)
                         .set_symbol(symbol)
-                        .set_style(*bar_style);
-                    }
+                        .set_style(bar_style);
+                }
+            }
+        };
 
-                    i += 1;
-                    if *d > 8 {
-                        *d -= 8;
-                    } else {
-                        *d = 0;
+        match self.grouping {
+            BarGrouping::Grouped => {
+                let mut x_offset = 0u16;
+                for (i, d) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    for (data_type, &value) in d.iter().enumerate() {
+                        let bar_style = *self.bar_styles.get(data_type).unwrap_or(&defaul_style);
+                        let left = chart_area.left()
+                            + (i * bars_per_column + data_type) as u16
+                                * (self.bar_width + self.bar_gap)
+                            + x_offset;
+                        draw_segment(buf, left, baseline, value >= 0, grouped_eighths_of(value), bar_style);
+                    }
+                    x_offset += self.group_gap;
+                }
+            }
+            BarGrouping::Stacked => {
+                for (col, column) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    let mut pos_row = baseline;
+                    let mut neg_row = baseline;
+                    let left = chart_area.left() + col as u16 * (self.bar_width + self.group_gap);
+                    for (data_type, &value) in column.iter().enumerate() {
+                        let bar_style = *self.bar_styles.get(data_type).unwrap_or(&defaul_style);
+                        if value >= 0 {
+                            // Clamp to whatever room is left in the column so rounding error
+                            // across several segments can't push `pos_row` past the chart top.
+                            let available = pos_row.saturating_sub(chart_area.top());
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_rows = eighths.div_ceil(8) as u16;
+                            draw_segment(buf, left, pos_row, true, eighths, bar_style);
+                            pos_row -= segment_rows;
+                        } else {
+                            let available = (baseline + negative_rows).saturating_sub(neg_row);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_rows = eighths.div_ceil(8) as u16;
+                            draw_segment(buf, left, neg_row, false, eighths, bar_style);
+                            neg_row += segment_rows;
+                        }
                     }
                 }
-                x_offset += self.group_gap;
             }
         }
 
-        let mut i = max_index * bars_per_column;
-        let mut x_offset = self.group_gap * max_index as u16;
-
-        for d in self.data.into_iter().take(max_index).rev() {
-            x_offset -= self.group_gap;
-            for (data_type, value) in d.into_iter().enumerate().rev() {
-                i -= 1;
-                if value != 0 {
-                    let value_label = (self.format)(value);
-                    let width = value_label.width() as u16;
-                    let style = self.value_styles.get(data_type).unwrap_or(&defaul_style);
-                    buf.set_string(
-                        chart_area.left()
-                            + i as u16 * (self.bar_width + self.bar_gap)
-                            + x_offset
-                            + (self.bar_width.saturating_sub(width) >> 1),
-                        chart_area.bottom() - 2 - data_type as u16,
-                        value_label,
-                        *style,
-                    );
+        match self.grouping {
+            BarGrouping::Grouped => {
+                let mut x_offset = 0u16;
+                for (i, d) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    for (data_type, &value) in d.iter().enumerate() {
+                        if value == 0 {
+                            continue;
+                        }
+                        let value_label = (self.format)(value);
+                        let width = value_label.width() as u16;
+                        let style = self.value_styles.get(data_type).unwrap_or(&defaul_style);
+                        let rows = grouped_eighths_of(value).div_ceil(8) as u16;
+                        let y = if value > 0 {
+                            baseline.saturating_sub(1 + rows).max(chart_area.top())
+                        } else {
+                            (baseline + rows).min(chart_area.bottom() - 2)
+                        };
+                        let left = chart_area.left()
+                            + (i * bars_per_column + data_type) as u16
+                                * (self.bar_width + self.bar_gap)
+                            + x_offset;
+                        buf.set_string(
+                            left + (self.bar_width.saturating_sub(width) >> 1),
+                            y,
+                            value_label,
+                            *style,
+                        );
+                    }
+                    x_offset += self.group_gap;
+                }
+            }
+            BarGrouping::Stacked => {
+                for (col, column) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    let mut pos_row = baseline;
+                    let mut neg_row = baseline;
+                    let left = chart_area.left() + col as u16 * (self.bar_width + self.group_gap);
+                    for (data_type, &value) in column.iter().enumerate() {
+                        if value == 0 {
+                            continue;
+                        }
+                        let value_label = (self.format)(value);
+                        let width = value_label.width() as u16;
+                        let style = self.value_styles.get(data_type).unwrap_or(&defaul_style);
+                        // Centered within the segment, mirroring the same clamp the fill pass
+                        // uses so the label never drifts past where its segment actually ends.
+                        let y = if value > 0 {
+                            let available = pos_row.saturating_sub(chart_area.top());
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_rows = eighths.div_ceil(8) as u16;
+                            let mid = pos_row - segment_rows + segment_rows / 2;
+                            pos_row -= segment_rows;
+                            mid
+                        } else {
+                            let available = (baseline + negative_rows).saturating_sub(neg_row);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_rows = eighths.div_ceil(8) as u16;
+                            let mid = neg_row + segment_rows / 2;
+                            neg_row += segment_rows;
+                            mid
+                        };
+                        buf.set_string(
+                            left + (self.bar_width.saturating_sub(width) >> 1),
+                            y,
+                            value_label,
+                            *style,
+                        );
+                    }
                 }
             }
         }
 
-        let label_max_width =
-            bars_per_column as u16 * self.bar_width + (bars_per_column as u16 - 1) * self.bar_gap;
-        for (i, label) in self.labels.iter().take(max_index).enumerate() {
+        let label_max_width = match self.grouping {
+            BarGrouping::Grouped => {
+                bars_per_column as u16 * self.bar_width + (bars_per_column as u16 - 1) * self.bar_gap
+            }
+            BarGrouping::Stacked => self.bar_width,
+        };
+        for (i, label) in self.labels.iter().skip(scroll_offset).take(max_index).enumerate() {
+            let x = match self.grouping {
+                BarGrouping::Grouped => {
+                    chart_area.left()
+                        + (i * bars_per_column) as u16 * (self.bar_width + self.bar_gap)
+                        + (self.group_gap * i as u16)
+                }
+                BarGrouping::Stacked => {
+                    chart_area.left() + i as u16 * (self.bar_width + self.group_gap)
+                }
+            };
             buf.set_stringn(
-                chart_area.left()
-                    + (i * bars_per_column) as u16 * (self.bar_width + self.bar_gap)
-                    + (self.group_gap * i as u16)
-                    + (label_max_width.saturating_sub(label.len() as u16) >> 1),
+                x + (label_max_width.saturating_sub(label.len() as u16) >> 1),
                 chart_area.bottom() - 1,
                 label,
                 label_max_width as usize,
@@ -281,6 +626,365 @@ impl<'a> Widget for BarChart2<'a> {
             );
         }
     }
+
+    fn render_horizontal(self, area: Rect, buf: &mut Buffer) {
+        let show_legend =
+            self.show_legend && !self.series_names.is_empty() && area.height > 2;
+        if show_legend {
+            self.render_legend(Rect::new(area.x, area.y, area.width, 1), buf);
+        }
+        let legend_rows = u16::from(show_legend);
+        let chart_area = Rect::new(
+            area.x,
+            area.y + legend_rows,
+            area.width,
+            area.height - legend_rows,
+        );
+
+        let bars_per_column = self.data[0].len();
+        let scroll_offset = min(self.scroll_offset, self.data.len() - 1);
+
+        let row_footprint = match self.grouping {
+            BarGrouping::Grouped => {
+                (self.bar_width + self.bar_gap) * bars_per_column as u16 + self.group_gap
+            }
+            BarGrouping::Stacked => self.bar_width + self.group_gap,
+        };
+
+        let max_index = min(
+            (chart_area.height + self.group_gap + self.bar_gap) as usize / row_footprint as usize,
+            self.data.len() - scroll_offset,
+        );
+
+        let label_width = self
+            .labels
+            .iter()
+            .skip(scroll_offset)
+            .take(max_index)
+            .map(|l| l.width() as u16)
+            .max()
+            .unwrap_or(0);
+        let length = chart_area.width.saturating_sub(label_width + 1);
+
+        // See the vertical renderer for why `Stacked` sizes against the column sum rather than
+        // the single largest value.
+        let (default_max_positive, default_max_negative) = match self.grouping {
+            BarGrouping::Grouped => (
+                self.data
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|&v| v > 0)
+                    .map(|v| v.unsigned_abs())
+                    .max()
+                    .unwrap_or(0),
+                self.data
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|&v| v < 0)
+                    .map(|v| v.unsigned_abs())
+                    .max()
+                    .unwrap_or(0),
+            ),
+            BarGrouping::Stacked => (
+                self.data
+                    .iter()
+                    .map(|column| {
+                        column
+                            .iter()
+                            .copied()
+                            .filter(|&v| v > 0)
+                            .map(|v| v.unsigned_abs())
+                            .sum::<u64>()
+                    })
+                    .max()
+                    .unwrap_or(0),
+                self.data
+                    .iter()
+                    .map(|column| {
+                        column
+                            .iter()
+                            .copied()
+                            .filter(|&v| v < 0)
+                            .map(|v| v.unsigned_abs())
+                            .sum::<u64>()
+                    })
+                    .max()
+                    .unwrap_or(0),
+            ),
+        };
+        // Only let a custom `max()` override a direction that actually has data to cap —
+        // otherwise an all-positive chart would lose half its rows to an unreachable negative
+        // zone just because the caller set a ceiling.
+        let max_positive = if default_max_positive > 0 {
+            self.max.unwrap_or(default_max_positive)
+        } else {
+            0
+        };
+        let max_negative = if default_max_negative > 0 {
+            self.max.unwrap_or(default_max_negative)
+        } else {
+            0
+        };
+
+        let magnitude_total = max_positive + max_negative;
+        let positive_cols = if magnitude_total == 0 {
+            length
+        } else {
+            min(
+                (u64::from(length) * max_positive / magnitude_total) as u16,
+                length,
+            )
+        };
+        let negative_cols = length - positive_cols;
+
+        let defaul_style = Style::default();
+
+        // See the vertical renderer for why negative bars reuse the left-aligned glyph set too.
+        let eighths_to_symbol = |eighths: u64| match eighths {
+            0 => HORIZONTAL_NINE_LEVELS.empty,
+            1 => HORIZONTAL_NINE_LEVELS.one_eighth,
+            2 => HORIZONTAL_NINE_LEVELS.one_quarter,
+            3 => HORIZONTAL_NINE_LEVELS.three_eighths,
+            4 => HORIZONTAL_NINE_LEVELS.half,
+            5 => HORIZONTAL_NINE_LEVELS.five_eighths,
+            6 => HORIZONTAL_NINE_LEVELS.three_quarters,
+            7 => HORIZONTAL_NINE_LEVELS.seven_eighths,
+            _ => HORIZONTAL_NINE_LEVELS.full,
+        };
+
+        let eighths_of = |value: i64| -> u64 {
+            if value > 0 && max_positive > 0 {
+                value.unsigned_abs() * u64::from(positive_cols) * 8 / max_positive
+            } else if value < 0 && max_negative > 0 {
+                value.unsigned_abs() * u64::from(negative_cols) * 8 / max_negative
+            } else {
+                0
+            }
+        };
+
+        // See the vertical renderer for why `Grouped` bars need this clamp too.
+        let grouped_eighths_of = |value: i64| -> u64 {
+            let available = if value >= 0 {
+                u64::from(positive_cols) * 8
+            } else {
+                u64::from(negative_cols) * 8
+            };
+            min(eighths_of(value), available)
+        };
+
+        let bar_left = chart_area.left() + label_width + 1;
+        // Positive bars grow rightward (increasing x) from the baseline, so (unlike the vertical
+        // renderer, where "up" is the decreasing direction) the positive zone is the one whose
+        // capacity is measured from the baseline to the far edge, putting the baseline at
+        // `negative_cols` in from `bar_left`.
+        let baseline = bar_left + negative_cols;
+
+        // A visible zero line: columns left of it are negative, columns at and right of it are
+        // positive, so a bar with no opposite-sign sibling still shows where zero sits.
+        for y in chart_area.top()..chart_area.bottom() {
+            buf.get_mut(baseline, y)
+                .set_symbol(symbols::line::NORMAL.vertical)
+                .set_style(self.label_style);
+        }
+
+        // `edge` is the baseline-adjacent column the segment grows away from: rightward if
+        // `positive`, leftward otherwise.
+        let draw_segment = |buf: &mut Buffer, top: u16, edge: u16, positive: bool, eighths: u64, bar_style: Style| {
+            let full_cols = (eighths / 8) as u16;
+            let remainder = (eighths % 8) as u16;
+            for col in 0..full_cols {
+                let x = if positive { edge + col } else { edge - 1 - col };
+                for y in 0..self.bar_width {
+                    buf.get_mut(x, top + y)
+                        .set_symbol(HORIZONTAL_NINE_LEVELS.full)
+                        .set_style(bar_style);
+                }
+            }
+            if remainder > 0 {
+                let x = if positive {
+                    edge + full_cols
+                } else {
+                    edge - 1 - full_cols
+                };
+                let symbol = eighths_to_symbol(u64::from(remainder));
+                for y in 0..self.bar_width {
+                    buf.get_mut(x, top + y).set_symbol(symbol).set_style(bar_style);
+                }
+            }
+        };
+
+        match self.grouping {
+            BarGrouping::Grouped => {
+                let mut y_offset = 0u16;
+                for (i, d) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    for (data_type, &value) in d.iter().enumerate() {
+                        let bar_style = *self.bar_styles.get(data_type).unwrap_or(&defaul_style);
+                        let top = chart_area.top()
+                            + (i * bars_per_column + data_type) as u16
+                                * (self.bar_width + self.bar_gap)
+                            + y_offset;
+                        draw_segment(buf, top, baseline, value >= 0, grouped_eighths_of(value), bar_style);
+                    }
+                    y_offset += self.group_gap;
+                }
+            }
+            BarGrouping::Stacked => {
+                for (row, column) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    let mut pos_col = baseline;
+                    let mut neg_col = baseline;
+                    let top = chart_area.top() + row as u16 * (self.bar_width + self.group_gap);
+                    for (data_type, &value) in column.iter().enumerate() {
+                        let bar_style = *self.bar_styles.get(data_type).unwrap_or(&defaul_style);
+                        if value >= 0 {
+                            // Clamp to whatever room is left in the column so rounding error
+                            // across several segments can't push `pos_col` past the chart edge.
+                            let available = (baseline + positive_cols).saturating_sub(pos_col);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_cols = eighths.div_ceil(8) as u16;
+                            draw_segment(buf, top, pos_col, true, eighths, bar_style);
+                            pos_col += segment_cols;
+                        } else {
+                            let available = neg_col.saturating_sub(bar_left);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_cols = eighths.div_ceil(8) as u16;
+                            draw_segment(buf, top, neg_col, false, eighths, bar_style);
+                            neg_col -= segment_cols;
+                        }
+                    }
+                }
+            }
+        }
+
+        match self.grouping {
+            BarGrouping::Grouped => {
+                let mut y_offset = 0u16;
+                for (i, d) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    for (data_type, &value) in d.iter().enumerate() {
+                        if value == 0 {
+                            continue;
+                        }
+                        let value_label = (self.format)(value);
+                        let cols = grouped_eighths_of(value).div_ceil(8) as u16;
+                        let style = self.value_styles.get(data_type).unwrap_or(&defaul_style);
+                        let top = chart_area.top()
+                            + (i * bars_per_column + data_type) as u16
+                                * (self.bar_width + self.bar_gap)
+                            + y_offset;
+                        let x = if value > 0 {
+                            (baseline + cols).min(chart_area.right() - 1)
+                        } else {
+                            baseline
+                                .saturating_sub(cols + value_label.width() as u16)
+                                .max(chart_area.left())
+                        };
+                        buf.set_string(x, top, value_label, *style);
+                    }
+                    y_offset += self.group_gap;
+                }
+            }
+            BarGrouping::Stacked => {
+                for (row, column) in self
+                    .data
+                    .iter()
+                    .skip(scroll_offset)
+                    .take(max_index)
+                    .enumerate()
+                {
+                    let mut pos_col = baseline;
+                    let mut neg_col = baseline;
+                    let top = chart_area.top() + row as u16 * (self.bar_width + self.group_gap);
+                    for (data_type, &value) in column.iter().enumerate() {
+                        if value == 0 {
+                            continue;
+                        }
+                        let value_label = (self.format)(value);
+                        let style = self.value_styles.get(data_type).unwrap_or(&defaul_style);
+                        // Centered within the segment, mirroring the same clamp the fill pass
+                        // uses so the label never drifts past where its segment actually ends.
+                        let x = if value > 0 {
+                            let available = (baseline + positive_cols).saturating_sub(pos_col);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_cols = eighths.div_ceil(8) as u16;
+                            let mid = pos_col + segment_cols / 2;
+                            pos_col += segment_cols;
+                            mid
+                        } else {
+                            let available = neg_col.saturating_sub(bar_left);
+                            let eighths = min(eighths_of(value), u64::from(available) * 8);
+                            let segment_cols = eighths.div_ceil(8) as u16;
+                            neg_col -= segment_cols;
+                            neg_col + segment_cols / 2
+                        };
+                        buf.set_string(x, top, value_label, *style);
+                    }
+                }
+            }
+        }
+
+        for (row, label) in self.labels.iter().skip(scroll_offset).take(max_index).enumerate() {
+            let y = match self.grouping {
+                BarGrouping::Grouped => {
+                    chart_area.top()
+                        + (row * bars_per_column) as u16 * (self.bar_width + self.bar_gap)
+                        + self.group_gap * row as u16
+                }
+                BarGrouping::Stacked => {
+                    chart_area.top() + row as u16 * (self.bar_width + self.group_gap)
+                }
+            };
+            buf.set_stringn(
+                chart_area.left(),
+                y,
+                label,
+                label_width as usize,
+                self.label_style,
+            );
+        }
+    }
+
+    /// Renders a single-row legend of colored swatches followed by each series' name.
+    fn render_legend(&self, area: Rect, buf: &mut Buffer) {
+        let defaul_style = Style::default();
+        let mut x = area.left();
+        for (i, name) in self.series_names.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            let swatch_style = self.bar_styles.get(i).unwrap_or(&defaul_style);
+            buf.get_mut(x, area.top())
+                .set_symbol("■")
+                .set_style(*swatch_style);
+            x += 2;
+
+            let remaining = area.right().saturating_sub(x);
+            if remaining == 0 {
+                break;
+            }
+            let width = min(name.width() as u16, remaining);
+            buf.set_stringn(x, area.top(), name, width as usize, self.label_style);
+            x += width + 2;
+        }
+    }
 }
 
 impl<'a> SizeHint for BarChart2<'a> {
@@ -297,3 +1001,114 @@ impl<'a> SizeHint for BarChart2<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacked_bars_render_without_panicking() {
+        let chart = BarChart2::default()
+            .grouping(BarGrouping::Stacked)
+            .add_data(&[3, 5])
+            .add_data(&[4, 2])
+            .bar_width(3)
+            .labels(&["a", "b"]);
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+    }
+
+    #[test]
+    fn horizontal_direction_renders_without_panicking() {
+        let chart = BarChart2::default()
+            .direction(Direction::Horizontal)
+            .add_data(&[9, 12, 5, 8])
+            .labels(&["30°C", "50°C", "60°C", "80°C"])
+            .bar_width(2);
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+    }
+
+    #[test]
+    fn scroll_offset_pages_through_overflowing_groups_without_panicking() {
+        let chart = BarChart2::default()
+            .add_data(&[9, 12, 5, 8, 3, 7])
+            .labels(&["a", "b", "c", "d", "e", "f"])
+            .bar_width(1)
+            .scroll_offset(4);
+        let area = Rect::new(0, 0, 6, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+    }
+
+    #[test]
+    fn legend_renders_without_panicking() {
+        let styles = [
+            Style::default().fg(crate::style::Color::Green),
+            Style::default().fg(crate::style::Color::Yellow),
+        ];
+        let chart = BarChart2::default()
+            .add_data(&[9, 12])
+            .add_data(&[6, 11])
+            .bar_styles(&styles)
+            .series_names(&["Data1", "Data2"])
+            .show_legend(true)
+            .bar_width(3);
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+    }
+
+    #[test]
+    fn stacked_segments_fit_their_column_sum_budget() {
+        // Two positive series that individually top out at 10 and 8, but whose column sum (18)
+        // is what a stacked column actually needs to fit within `positive_rows`. Scaling each
+        // segment against the largest *single* value (10) instead of the column sum would give
+        // the first segment the whole column and leave no room for the second.
+        let styles = [
+            Style::default().fg(crate::style::Color::Green),
+            Style::default().fg(crate::style::Color::Yellow),
+        ];
+        let chart = BarChart2::default()
+            .grouping(BarGrouping::Stacked)
+            .add_data(&[10])
+            .add_data(&[8])
+            .bar_styles(&styles)
+            .bar_width(1);
+        let area = Rect::new(0, 0, 3, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+        // 9 bar rows above the baseline: the first segment takes 10/18 of them (rows 8..=4) and
+        // the second the remaining 4 (rows 3..=0), ending exactly at the top of the chart.
+        assert_eq!(buf.get(0, 4).fg, crate::style::Color::Green);
+        assert_eq!(buf.get(0, 3).fg, crate::style::Color::Yellow);
+        assert_eq!(buf.get(0, 0).fg, crate::style::Color::Yellow);
+    }
+
+    #[test]
+    fn grouped_bar_past_a_custom_max_clips_instead_of_panicking() {
+        // A custom `max()` smaller than the data used to scale `eighths_of` past the rows
+        // actually available, underflowing `draw_segment`'s `u16` row cursor.
+        let chart = BarChart2::default().add_data(&[20]).max(5).bar_width(1);
+        let area = Rect::new(0, 0, 3, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+    }
+
+    #[test]
+    fn all_positive_data_with_a_custom_max_keeps_the_full_height() {
+        // A custom `max()` used to force both `max_positive` and `max_negative` to the same
+        // value even when one side had no data, halving the rows available to an all-positive
+        // chart for a negative zone that could never be reached.
+        let chart = BarChart2::default().add_data(&[3]).max(5).bar_width(1);
+        let area = Rect::new(0, 0, 3, 10);
+        let mut buf = Buffer::empty(area);
+        chart.render(area, &mut buf);
+        // All 9 bar rows are positive, so 3/5 of them is 43 eighths: full rows 8..=4 and a
+        // three-eighths row at 3. With the height halved, row 4 would be the baseline instead.
+        assert_eq!(buf.get(0, 4).symbol, symbols::bar::NINE_LEVELS.full);
+        assert_eq!(buf.get(0, 3).symbol, symbols::bar::NINE_LEVELS.three_eighths);
+    }
+}